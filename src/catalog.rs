@@ -0,0 +1,187 @@
+//! Stream-parsing of a catalog containing many concatenated TLEs.
+
+use std::io::BufRead;
+
+use crate::{Error, Line, Tle};
+
+/// An error produced while iterating a catalog with [`Tle::parse_many`].
+///
+/// A parse failure reports the 0-based index of the record that failed;
+/// a read failure of the underlying stream ([`CatalogError::Io`]) carries
+/// no record index.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum CatalogError {
+    /// Reading a line from the underlying stream failed.
+    Io(std::io::Error),
+    /// The element set at `record` failed to parse.
+    Tle {
+        /// The 0-based index of the record within the stream.
+        record: usize,
+        source: Error,
+    },
+}
+
+impl std::fmt::Display for CatalogError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CatalogError::Io(err) => write!(f, "failed to read TLE catalog: {err}"),
+            CatalogError::Tle { record, source } => write!(f, "record {record}: {source}"),
+        }
+    }
+}
+
+impl std::error::Error for CatalogError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CatalogError::Io(err) => Some(err),
+            CatalogError::Tle { source, .. } => Some(source),
+        }
+    }
+}
+
+/// Iterator returned by [`Tle::parse_many`].
+///
+/// Yields `(name, tle)` pairs, where `name` is `Some` when the record was
+/// preceded by a satellite-name line (the common 3-line catalog variant,
+/// optionally prefixed with `0 `). Blank lines between records are skipped.
+/// After a malformed record, the iterator keeps going with the next one
+/// rather than stopping.
+pub struct ParseMany<R> {
+    lines: std::io::Lines<R>,
+    record: usize,
+}
+
+impl<R: BufRead> ParseMany<R> {
+    fn next_nonblank_line(&mut self) -> Option<std::io::Result<String>> {
+        loop {
+            match self.lines.next()? {
+                Ok(line) if line.trim().is_empty() => continue,
+                other => return Some(other),
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for ParseMany<R> {
+    type Item = Result<(Option<String>, Tle), CatalogError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = match self.next_nonblank_line()? {
+            Ok(line) => line,
+            Err(err) => return Some(Err(CatalogError::Io(err))),
+        };
+
+        let record = self.record;
+        self.record += 1;
+
+        let (name, line1) = if first.starts_with("1 ") {
+            (None, first)
+        } else {
+            let name = first.trim_start_matches("0 ").trim().to_string();
+            match self.next_nonblank_line() {
+                Some(Ok(line)) => (Some(name), line),
+                Some(Err(err)) => return Some(Err(CatalogError::Io(err))),
+                None => {
+                    return Some(Err(CatalogError::Tle {
+                        record,
+                        source: Error::InvalidLineSize(Line::Line1, 0),
+                    }))
+                }
+            }
+        };
+
+        let line2 = match self.next_nonblank_line() {
+            Some(Ok(line)) => line,
+            Some(Err(err)) => return Some(Err(CatalogError::Io(err))),
+            None => {
+                return Some(Err(CatalogError::Tle {
+                    record,
+                    source: Error::InvalidLineSize(Line::Line2, 0),
+                }))
+            }
+        };
+
+        match Tle::parse(line1.as_bytes(), line2.as_bytes()) {
+            Ok(tle) => Some(Ok((name, tle))),
+            Err(source) => Some(Err(CatalogError::Tle { record, source })),
+        }
+    }
+}
+
+impl Tle {
+    /// Stream-parses a catalog of many concatenated TLEs, as distributed by
+    /// catalogs such as Space-Track's GP data.
+    ///
+    /// Each record is either two lines (just the element set) or three
+    /// lines (a satellite-name line, often prefixed with `0 `, followed by
+    /// the element set); both are recognized automatically. The returned
+    /// iterator keeps going after a malformed record so that the caller can
+    /// decide whether to stop. A record that fails to parse is reported with
+    /// its 0-based index; a read failure ([`CatalogError::Io`]) is reported
+    /// without one.
+    pub fn parse_many<R: BufRead>(reader: R) -> ParseMany<R> {
+        ParseMany {
+            lines: reader.lines(),
+            record: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const ISS: (&str, &str) = (
+        "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+        "2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+    );
+    const NOAA_14: (&str, &str) = (
+        "1 23455U 94089A   97320.90946019  .00000140  00000-0  10191-3 0  2621",
+        "2 23455  99.0090 272.6745 0008546 223.1686 136.8816 14.11711747148495",
+    );
+
+    #[test]
+    fn parses_bare_two_line_records() {
+        let catalog = format!("{}\n{}\n{}\n{}\n", ISS.0, ISS.1, NOAA_14.0, NOAA_14.1);
+        let records: Vec<_> = Tle::parse_many(Cursor::new(catalog)).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0, None);
+        assert_eq!(records[0].1.satellite_catalog_number, 25544);
+        assert_eq!(records[1].1.satellite_catalog_number, 23455);
+    }
+
+    #[test]
+    fn parses_three_line_records_with_name() {
+        let catalog = format!("ISS (ZARYA)\n{}\n{}\n0 NOAA 14\n{}\n{}\n", ISS.0, ISS.1, NOAA_14.0, NOAA_14.1);
+        let records: Vec<_> = Tle::parse_many(Cursor::new(catalog)).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].0.as_deref(), Some("ISS (ZARYA)"));
+        assert_eq!(records[1].0.as_deref(), Some("NOAA 14"));
+    }
+
+    #[test]
+    fn skips_blank_lines_between_records() {
+        let catalog = format!("\n{}\n{}\n\n\n{}\n{}\n\n", ISS.0, ISS.1, NOAA_14.0, NOAA_14.1);
+        let records: Vec<_> = Tle::parse_many(Cursor::new(catalog)).collect::<Result<_, _>>().unwrap();
+        assert_eq!(records.len(), 2);
+    }
+
+    #[test]
+    fn keeps_going_after_a_malformed_record() {
+        // A too-short first line fails to parse as a TLE, but the iterator
+        // should still yield the well-formed record that follows.
+        let catalog = format!("1 too short\n2 also too short\n{}\n{}\n", ISS.0, ISS.1);
+        let mut records = Tle::parse_many(Cursor::new(catalog));
+
+        let first = records.next().unwrap();
+        assert!(matches!(first, Err(CatalogError::Tle { record: 0, .. })));
+
+        let second = records.next().unwrap().unwrap();
+        assert_eq!(second.1.satellite_catalog_number, 25544);
+
+        assert!(records.next().is_none());
+    }
+}
@@ -1,5 +1,11 @@
 #![forbid(unsafe_code)]
 
+mod catalog;
+mod sgp4;
+
+pub use catalog::{CatalogError, ParseMany};
+pub use sgp4::{GravityModel, PropagationError, StateVector};
+
 const DECIMAL_RADIX: u32 = 10;
 
 /// Some errors are ambiguous as to the line in which they occur.
@@ -88,8 +94,115 @@ pub enum Error {
     SatelliteCatalogNumberMismatch(u32, u32),
     /// TLEs are required to contain only valid ASCII characters
     ContainsNonAsciiCharacter(Line),
+    /// The satellite catalog number used the Alpha-5 extended encoding (its
+    /// first character was a letter rather than a digit), but that letter
+    /// was not one of the 24 valid Alpha-5 letters.
+    ///
+    /// `I` and `O` are excluded as ambiguous with the digits `1` and `0`,
+    /// and lowercase letters are rejected outright.
+    Alpha5CatalogNumber(Line, char),
+    /// [`Tle::write`] was asked to encode a `satellite_catalog_number`
+    /// greater than 339999, the largest value representable by the Alpha-5
+    /// extended encoding (`Z9999`).
+    SatelliteCatalogNumberOutOfRange(u32),
+    /// [`Tle::write`] was asked to encode a `b_star` or
+    /// `second_derivative_of_mean_motion` magnitude whose power-of-ten
+    /// exponent does not fit in the format's single exponent digit
+    /// (roughly, a nonzero magnitude below `1e-10` or at or above `1e9`).
+    DecimalExponentOutOfRange(i32),
+    /// [`Tle::epoch_datetime`] was asked to resolve an
+    /// `epoch_day_and_fractional_part` whose truncated day-of-year
+    /// component is `0` or greater than the number of days in
+    /// `epoch_year` (365, or 366 in a leap year).
+    EpochDayOutOfRange(u32),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::InvalidLineSize(line, len) => write!(
+                f,
+                "{line:?} must be {} characters long, found {len}",
+                Tle::LINE_LEN
+            ),
+            Error::Space(line, found, pos) => {
+                write!(f, "{line:?} column {pos} must be a space, found '{found}'")
+            }
+            Error::SatlliteCatalogNumber(line) => {
+                write!(f, "{line:?} contains an invalid satellite catalog number")
+            }
+            Error::Classification(found) => {
+                write!(f, "classification must be 'U', 'C', or 'S', found '{found}'")
+            }
+            Error::InternationalDesignatorLaunchYear => write!(
+                f,
+                "failed to parse the international designator's launch year"
+            ),
+            Error::InternationalDesignatorLaunchNumber => write!(
+                f,
+                "failed to parse the international designator's launch number"
+            ),
+            Error::EpochYear => write!(f, "failed to parse the epoch year"),
+            Error::EpochDay => write!(f, "failed to parse the epoch day"),
+            Error::FirstDerivative => {
+                write!(f, "failed to parse the first derivative of mean motion")
+            }
+            Error::SecondDerivative => {
+                write!(f, "failed to parse the second derivative of mean motion")
+            }
+            Error::BStar => write!(f, "failed to parse the B* drag term"),
+            Error::EphemerisType(found) => {
+                write!(f, "ephemeris type must be '0', found '{found}'")
+            }
+            Error::ElementSetNumber => write!(f, "failed to parse the element set number"),
+            Error::Inclination => write!(f, "failed to parse the inclination"),
+            Error::RightAscension => {
+                write!(f, "failed to parse the right ascension of the ascending node")
+            }
+            Error::Eccentricty => write!(f, "failed to parse the eccentricity"),
+            Error::ArgumentOfPerigee => write!(f, "failed to parse the argument of perigee"),
+            Error::MeanAnomaly => write!(f, "failed to parse the mean anomaly"),
+            Error::MeanMotion => write!(f, "failed to parse the mean motion"),
+            Error::RevolutionNumber => write!(f, "failed to parse the revolution number at epoch"),
+            Error::Checksum(line, found) => {
+                write!(f, "{line:?} checksum must be a digit, found '{found}'")
+            }
+            Error::InvalidChecksum(line, found, expected) => write!(
+                f,
+                "{line:?} checksum {found} does not match the calculated checksum {expected}"
+            ),
+            Error::LineNumber(line, found) => {
+                write!(f, "{line:?} must begin with its line number, found '{found}'")
+            }
+            Error::SatelliteCatalogNumberMismatch(line1, line2) => write!(
+                f,
+                "satellite catalog number mismatch: line 1 has {line1}, line 2 has {line2}"
+            ),
+            Error::ContainsNonAsciiCharacter(line) => {
+                write!(f, "{line:?} contains a non-ASCII character")
+            }
+            Error::Alpha5CatalogNumber(line, found) => write!(
+                f,
+                "{line:?} has an invalid Alpha-5 catalog number letter '{found}'"
+            ),
+            Error::SatelliteCatalogNumberOutOfRange(found) => write!(
+                f,
+                "satellite catalog number {found} exceeds 339999, the largest value the Alpha-5 encoding can represent"
+            ),
+            Error::DecimalExponentOutOfRange(exponent) => write!(
+                f,
+                "exponent {exponent} does not fit in the format's single exponent digit"
+            ),
+            Error::EpochDayOutOfRange(day) => write!(
+                f,
+                "epoch day-of-year {day} is 0 or exceeds the number of days in the epoch year"
+            ),
+        }
+    }
 }
 
+impl std::error::Error for Error {}
+
 #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct InternationalDesignator {
     pub launch_year: u8,
@@ -104,6 +217,10 @@ pub enum Classification {
     Secret,
 }
 
+/// `(year, month, day, hour, minute, second, nanosecond)`, as returned by
+/// [`Tle::epoch_datetime`].
+pub type EpochDateTime = (u16, u8, u8, u8, u8, u8, u32);
+
 /// A parsed and validate Two Line Element Set
 ///
 /// This is primarily generated via the `parse` method
@@ -157,8 +274,9 @@ impl Tle {
         let line = split_space!(Line::Line1, line, 1);
 
         let (slice, line) = line.split_at(5);
-        let Some(satellite_catalog_number_1) = as_digits(slice) else {
-            return Err(Error::SatlliteCatalogNumber(Line::Line1));
+        let satellite_catalog_number_1 = match parse_catalog_number(slice, Line::Line1) {
+            Ok(n) => n,
+            Err(error) => return Err(error),
         };
 
         let (slice, line) = line.split_at(1);
@@ -265,8 +383,9 @@ impl Tle {
         let line = split_space!(Line::Line1, line, 1);
 
         let (slice, line) = line.split_at(5);
-        let Some(satellite_catalog_number_2) = as_digits(slice) else {
-            return Err(Error::SatlliteCatalogNumber(Line::Line2));
+        let satellite_catalog_number_2 = match parse_catalog_number(slice, Line::Line2) {
+            Ok(n) => n,
+            Err(error) => return Err(error),
         };
 
         if satellite_catalog_number_1 != satellite_catalog_number_2 {
@@ -298,11 +417,10 @@ impl Tle {
         let Some(eccentricity) = as_digits(slice) else {
             return Err(Error::Eccentricty);
         };
-        let Some(dig) = eccentricity.checked_ilog10() else {
-            return Err(Error::Eccentricty);
-        };
-        let leading_zeroes = dig as i32 - slice.len() as i32;
-        let eccentricity = (eccentricity as f32).powi(leading_zeroes);
+        // The field holds an implied leading "0.", i.e. 7 digits of
+        // fractional part with no decimal point written, e.g. "0006703"
+        // means 0.0006703.
+        let eccentricity = eccentricity as f32 * 1.0e-7;
 
         let line = split_space!(Line::Line2, line, 33);
 
@@ -358,6 +476,261 @@ impl Tle {
 
         Ok(me)
     }
+
+    /// Re-encode this `Tle` back into its two fixed-width 69-character lines.
+    ///
+    /// This is the inverse of [`Tle::parse`]: every field is formatted back
+    /// into its fixed-column position and each line's trailing checksum is
+    /// recomputed rather than trusting whatever checksum was originally
+    /// parsed. This makes it possible to round-trip a parsed `Tle`, or to
+    /// produce a valid element set after mutating a field.
+    ///
+    /// `satellite_catalog_number` is a public field, so this returns
+    /// [`Error::SatelliteCatalogNumberOutOfRange`] rather than panicking if
+    /// it has been set above 339999, the largest value the Alpha-5 encoding
+    /// can represent. Likewise, `b_star` and `second_derivative_of_mean_motion`
+    /// can be set to a magnitude whose exponent doesn't fit in the format,
+    /// in which case this returns [`Error::DecimalExponentOutOfRange`].
+    pub fn write(&self) -> Result<[String; 2], Error> {
+        let catalog_number = format_catalog_number(self.satellite_catalog_number)?;
+
+        let mut line1 = String::with_capacity(Self::LINE_LEN);
+        line1.push('1');
+        line1.push(' ');
+        line1.push_str(&catalog_number);
+        line1.push(classification_char(self.classification));
+        line1.push(' ');
+        line1.push_str(&format!("{:02}", self.international_designator.launch_year));
+        line1.push_str(&format!("{:03}", self.international_designator.launch_num));
+        line1.extend(self.international_designator.launch_piece);
+        line1.push(' ');
+        line1.push_str(&format!("{:02}", self.epoch_year));
+        line1.push_str(&format!("{:012.8}", self.epoch_day_and_fractional_part));
+        line1.push(' ');
+        line1.push_str(&format_signed_decimal8(self.first_derivative_of_mean_motion));
+        line1.push(' ');
+        line1.push_str(&format_decimal_exponent(self.second_derivative_of_mean_motion)?);
+        line1.push(' ');
+        line1.push_str(&format_decimal_exponent(self.b_star)?);
+        line1.push(' ');
+        line1.push('0');
+        line1.push(' ');
+        line1.push_str(&format!("{:4}", self.element_set_number));
+        line1.push(checksum(&line1));
+
+        let mut line2 = String::with_capacity(Self::LINE_LEN);
+        line2.push('2');
+        line2.push(' ');
+        line2.push_str(&catalog_number);
+        line2.push(' ');
+        line2.push_str(&format!("{:8.4}", self.inclination));
+        line2.push(' ');
+        line2.push_str(&format!("{:8.4}", self.right_ascension_of_ascending_node));
+        line2.push(' ');
+        line2.push_str(&format!("{:07}", (self.eccentricity * 1.0e7).round() as u32));
+        line2.push(' ');
+        line2.push_str(&format!("{:8.4}", self.argument_of_perigee));
+        line2.push(' ');
+        line2.push_str(&format!("{:8.4}", self.mean_anomaly));
+        line2.push(' ');
+        line2.push_str(&format!("{:11.8}", self.mean_motion));
+        line2.push_str(&format!("{:05}", self.revolution_number_at_epoch));
+        line2.push(checksum(&line2));
+
+        Ok([line1, line2])
+    }
+
+    /// Resolves the TLE epoch into a calendar timestamp.
+    ///
+    /// The two-digit `epoch_year` is resolved with the standard pivot:
+    /// 57-99 maps to 1957-1999, and 00-56 maps to 2000-2056. Returns
+    /// `(year, month, day, hour, minute, second, nanosecond)`.
+    ///
+    /// `epoch_day_and_fractional_part` is a public field, so this returns
+    /// [`Error::EpochDayOutOfRange`] rather than producing a nonsensical
+    /// date if its truncated day-of-year component is 0 or exceeds the
+    /// number of days in `epoch_year`.
+    pub fn epoch_datetime(&self) -> Result<EpochDateTime, Error> {
+        let year = resolve_epoch_year(self.epoch_year);
+        let leap = is_leap_year(year);
+
+        let mut day = self.epoch_day_and_fractional_part.trunc() as u32;
+        if day == 0 || day > days_in_year(leap) {
+            return Err(Error::EpochDayOutOfRange(day));
+        }
+        let fraction_of_day = self.epoch_day_and_fractional_part.fract();
+
+        let mut month = 1u8;
+        for &days_in_this_month in &days_in_month(leap) {
+            if day <= days_in_this_month {
+                break;
+            }
+            day -= days_in_this_month;
+            month += 1;
+        }
+
+        // Rounded as a single integer quantity spanning the whole day,
+        // rather than rounding the seconds-with-fraction independently, so
+        // that `nanos` can never come out at 1_000_000_000 (one past its
+        // valid range) the way the old per-unit rounding could.
+        let total_nanos_of_day = (fraction_of_day * 86_400_000_000_000.0).round() as u64;
+        let total_nanos_of_day = total_nanos_of_day.min(86_399_999_999_999);
+        let hour = (total_nanos_of_day / 3_600_000_000_000) as u8;
+        let remainder = total_nanos_of_day % 3_600_000_000_000;
+        let minute = (remainder / 60_000_000_000) as u8;
+        let remainder = remainder % 60_000_000_000;
+        let second = (remainder / 1_000_000_000) as u8;
+        let nanos = (remainder % 1_000_000_000) as u32;
+
+        Ok((year, month, day as u8, hour, minute, second, nanos))
+    }
+
+    /// Converts the TLE epoch to a Julian date, via the standard
+    /// Gregorian-to-Julian-date formula.
+    pub fn epoch_julian_date(&self) -> Result<f64, Error> {
+        let (year, month, day, hour, minute, second, nanos) = self.epoch_datetime()?;
+        Ok(julian_date(year, month, day, hour, minute, second, nanos))
+    }
+}
+
+/// Resolves a TLE's two-digit epoch year with the standard pivot: 57-99 is
+/// 1957-1999, and 00-56 is 2000-2056.
+const fn resolve_epoch_year(epoch_year: u8) -> u16 {
+    if epoch_year >= 57 {
+        1900 + epoch_year as u16
+    } else {
+        2000 + epoch_year as u16
+    }
+}
+
+const fn is_leap_year(year: u16) -> bool {
+    (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400)
+}
+
+const fn days_in_month(leap: bool) -> [u32; 12] {
+    if leap {
+        [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    } else {
+        [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]
+    }
+}
+
+const fn days_in_year(leap: bool) -> u32 {
+    if leap {
+        366
+    } else {
+        365
+    }
+}
+
+/// Computes the Julian date for a Gregorian calendar timestamp, using the
+/// standard Meeus formula.
+fn julian_date(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8, nanos: u32) -> f64 {
+    let (y, m) = if month <= 2 {
+        (year as i32 - 1, month as i32 + 12)
+    } else {
+        (year as i32, month as i32)
+    };
+    let a = y / 100;
+    let b = 2 - a + a / 4;
+    let day_fraction = (hour as f64 * 3600.0 + minute as f64 * 60.0 + second as f64 + nanos as f64 / 1.0e9)
+        / 86_400.0;
+
+    (365.25 * (y as f64 + 4716.0)).floor() + (30.6001 * (m as f64 + 1.0)).floor() + day as f64 + day_fraction
+        + b as f64
+        - 1524.5
+}
+
+impl std::str::FromStr for Tle {
+    type Err = Error;
+
+    /// Parses a `Tle` from a single string containing both lines separated
+    /// by a newline, tolerating `\r\n` line endings and an optional
+    /// trailing newline.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let line1 = lines
+            .next()
+            .ok_or(Error::InvalidLineSize(Line::Line1, 0))?;
+        let line2 = lines
+            .next()
+            .ok_or(Error::InvalidLineSize(Line::Line2, 0))?;
+        Tle::parse(line1.as_bytes(), line2.as_bytes())
+    }
+}
+
+impl std::fmt::Display for Tle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let [line1, line2] = self.write().map_err(|_| std::fmt::Error)?;
+        writeln!(f, "{line1}")?;
+        write!(f, "{line2}")
+    }
+}
+
+fn classification_char(classification: Classification) -> char {
+    match classification {
+        Classification::Unclassified => 'U',
+        Classification::Classified => 'C',
+        Classification::Secret => 'S',
+    }
+}
+
+/// Formats a derivative-of-mean-motion style field: a sign (space for
+/// non-negative) followed by a decimal point and 8 digits, with the leading
+/// `0` before the point stripped, e.g. ` .00002182` or `-.00002182`.
+fn format_signed_decimal8(value: f32) -> String {
+    let sign = if value.is_sign_negative() { '-' } else { ' ' };
+    let formatted = format!("{:.8}", (value as f64).abs());
+    let fraction = formatted.trim_start_matches('0');
+    format!("{sign}{fraction}")
+}
+
+/// Formats a B*/second-derivative style implied-decimal field: a sign, a
+/// 5-digit mantissa, and a signed single-digit power-of-ten exponent, e.g.
+/// `-11606-4` for `-0.11606e-4`.
+///
+/// `b_star`/`second_derivative_of_mean_motion` are public fields, so this
+/// returns [`Error::DecimalExponentOutOfRange`] rather than silently
+/// writing a wrong value when the magnitude's exponent doesn't fit in the
+/// format's single exponent digit — the same failure mode as an
+/// out-of-range `satellite_catalog_number` gets from [`format_catalog_number`].
+fn format_decimal_exponent(value: f32) -> Result<String, Error> {
+    let sign = if value.is_sign_negative() { '-' } else { ' ' };
+    let abs = (value as f64).abs();
+    if abs == 0.0 {
+        // By convention a zero value is written with a `-` exponent sign
+        // (e.g. `00000-0`), not `+`.
+        return Ok(format!("{sign}00000-0"));
+    }
+    let (mantissa, exponent) = {
+        let mut exponent = abs.log10().floor() as i32 + 1;
+        let mut mantissa = (abs / 10f64.powi(exponent) * 100_000.0).round() as i64;
+        if mantissa >= 100_000 {
+            mantissa /= 10;
+            exponent += 1;
+        }
+        (mantissa as u32, exponent)
+    };
+    // The exponent column only ever holds a single digit.
+    if !(-9..=9).contains(&exponent) {
+        return Err(Error::DecimalExponentOutOfRange(exponent));
+    }
+    let exponent_sign = if exponent < 0 { '-' } else { '+' };
+    Ok(format!("{sign}{mantissa:05}{exponent_sign}{}", exponent.abs()))
+}
+
+/// Computes a TLE line checksum: the sum of all decimal digits plus 1 for
+/// every `-` character, modulo 10.
+fn checksum(partial_line: &str) -> char {
+    let sum: u32 = partial_line
+        .chars()
+        .map(|c| match c.to_digit(DECIMAL_RADIX) {
+            Some(digit) => digit,
+            None if c == '-' => 1,
+            None => 0,
+        })
+        .sum();
+    char::from_digit(sum % DECIMAL_RADIX, DECIMAL_RADIX).expect("sum % 10 is always a valid digit")
 }
 
 const fn trim_leading_space(line: &[char]) -> &[char] {
@@ -373,38 +746,32 @@ const fn trim_leading_space(line: &[char]) -> &[char] {
     slice
 }
 
+/// Parses an implied-decimal mantissa-and-exponent field, e.g. `28098-4` for
+/// `0.28098e-4`: a mantissa with an implied leading `0.`, followed by a
+/// signed single-digit power-of-ten exponent.
 fn parse_tle_f32(line: &[char]) -> Result<f32, Error> {
     let trimmed = trim_leading_space(line);
 
-    let mut idx = None;
-    let mut i = 0;
-    while i < trimmed.len() {
-        if trimmed[i] == '-' {
-            if idx.is_none() {
-                idx = Some(i);
-            } else {
-                return Err(Error::SecondDerivative);
-            }
-        }
-        i += 1;
+    if trimmed.len() < 2 {
+        return Err(Error::SecondDerivative);
     }
+    let (mantissa_and_sign, exponent_digit) = trimmed.split_at(trimmed.len() - 1);
+    let (mantissa, exponent_sign) = mantissa_and_sign.split_at(mantissa_and_sign.len() - 1);
 
-    let Some(idx) = idx else {
-        return Err(Error::SecondDerivative);
-    };
-    let (num, exp) = trimmed.split_at(idx);
-    let Some(num) = as_digits(num) else {
-        return Err(Error::SecondDerivative);
+    let exponent_sign = match exponent_sign[0] {
+        '-' => -1,
+        '+' => 1,
+        _ => return Err(Error::SecondDerivative),
     };
-    let Some((neg, exp)) = exp.split_first() else {
+    let Some(mantissa_digits) = as_digits(mantissa) else {
         return Err(Error::SecondDerivative);
     };
-    assert_eq!(*neg, '-');
-    let Some(exp) = as_digits(exp) else {
+    let Some(exponent_digit) = as_digits(exponent_digit) else {
         return Err(Error::SecondDerivative);
     };
+    let exponent = exponent_sign * exponent_digit as i32;
 
-    let val = (num as f32).powi(-(exp as i32));
+    let val = mantissa_digits as f32 * 10f32.powi(exponent - mantissa.len() as i32);
 
     Ok(val)
 }
@@ -454,6 +821,70 @@ const fn as_digits(chars: &[char]) -> Option<u32> {
     Some(val)
 }
 
+/// Parses a 5-character satellite catalog number field, decoding the
+/// Alpha-5 extended encoding when the first character is a letter: the
+/// letter maps to 10-33 (`A`=10 ... `Z`=33, skipping the ambiguous `I` and
+/// `O`) and is combined with the remaining 4 digits, e.g. `T2242` = 272242.
+fn parse_catalog_number(slice: &[char], line: Line) -> Result<u32, Error> {
+    let (&prefix, rest) = slice
+        .split_first()
+        .expect("catalog number field is always 5 characters");
+
+    if prefix.is_ascii_digit() {
+        return as_digits(slice).ok_or(Error::SatlliteCatalogNumber(line));
+    }
+    if !prefix.is_ascii_alphabetic() {
+        return Err(Error::SatlliteCatalogNumber(line));
+    }
+
+    let Some(letter_value) = alpha5_letter_value(prefix) else {
+        return Err(Error::Alpha5CatalogNumber(line, prefix));
+    };
+    let digits = as_digits(rest).ok_or(Error::SatlliteCatalogNumber(line))?;
+
+    Ok(letter_value * 10_000 + digits)
+}
+
+/// Maps an Alpha-5 letter to its 10-33 value, rejecting `I`, `O`, and
+/// lowercase letters.
+const fn alpha5_letter_value(c: char) -> Option<u32> {
+    match c {
+        'A'..='H' => Some(c as u32 - 'A' as u32 + 10),
+        'J'..='N' => Some(c as u32 - 'J' as u32 + 18),
+        'P'..='Z' => Some(c as u32 - 'P' as u32 + 23),
+        _ => None,
+    }
+}
+
+/// The inverse of [`alpha5_letter_value`].
+fn alpha5_letter(value: u32) -> Option<char> {
+    match value {
+        10..=17 => char::from_u32('A' as u32 + (value - 10)),
+        18..=22 => char::from_u32('J' as u32 + (value - 18)),
+        23..=33 => char::from_u32('P' as u32 + (value - 23)),
+        _ => None,
+    }
+}
+
+/// Formats a satellite catalog number into its 5-character field, emitting
+/// the Alpha-5 encoding for numbers >= 100000.
+///
+/// `satellite_catalog_number` is a public, freely-settable field, so this
+/// returns [`Error::SatelliteCatalogNumberOutOfRange`] rather than panicking
+/// when the value exceeds 339999 (`Z9999`), the largest the encoding can
+/// represent.
+fn format_catalog_number(catalog_number: u32) -> Result<String, Error> {
+    if catalog_number < 100_000 {
+        return Ok(format!("{catalog_number:05}"));
+    }
+
+    let letter_value = catalog_number / 10_000;
+    let remainder = catalog_number % 10_000;
+    let letter =
+        alpha5_letter(letter_value).ok_or(Error::SatelliteCatalogNumberOutOfRange(catalog_number))?;
+    Ok(format!("{letter}{remainder:04}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -470,6 +901,169 @@ mod tests {
         let _ = Tle::parse(line1, line2).unwrap();
     }
 
+    #[test]
+    fn from_str_parses_both_lines() {
+        let iss = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927\n2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle: Tle = iss.parse().unwrap();
+        assert_eq!(tle.satellite_catalog_number, 25544);
+
+        // Tolerates a trailing newline and \r\n line endings.
+        let with_trailing_newline = format!("{iss}\n");
+        assert_eq!(with_trailing_newline.parse::<Tle>().unwrap().satellite_catalog_number, 25544);
+        let with_crlf = iss.replace('\n', "\r\n");
+        assert_eq!(with_crlf.parse::<Tle>().unwrap().satellite_catalog_number, 25544);
+    }
+
+    #[test]
+    fn from_str_reports_missing_line() {
+        let err = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927"
+            .parse::<Tle>()
+            .unwrap_err();
+        assert_eq!(err, Error::InvalidLineSize(Line::Line2, 0));
+    }
+
+    #[test]
+    fn display_round_trips_through_from_str() {
+        let line1 = b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        let rendered = tle.to_string();
+        let round_tripped: Tle = rendered.parse().unwrap();
+        assert_eq!(tle.satellite_catalog_number, round_tripped.satellite_catalog_number);
+        assert!((tle.eccentricity - round_tripped.eccentricity).abs() < 1.0e-7);
+    }
+
+    #[test]
+    fn epoch_datetime_resolves_calendar_date() {
+        // ISS epoch "08264.51782528" -> 2008-09-20 12:25:40 UTC.
+        let line1 = b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        let (year, month, day, hour, minute, second, _nanos) = tle.epoch_datetime().unwrap();
+        assert_eq!((year, month, day, hour, minute, second), (2008, 9, 20, 12, 25, 40));
+    }
+
+    #[test]
+    fn epoch_julian_date_matches_known_value() {
+        let line1 = b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        assert!((tle.epoch_julian_date().unwrap() - 2_454_730.017_825_28).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn epoch_datetime_never_rounds_nanos_to_a_full_second() {
+        // This epoch day fraction (.01093750) is an entirely ordinary,
+        // spec-valid TLE field, but rounding hour/minute/second
+        // independently lands it within float rounding error of a whole
+        // second: the old implementation returned nanos == 1_000_000_000
+        // here, one past its documented 0..999_999_999 range.
+        let line1 = b"1 25544U 98067A   08264.01093750 -.00002182  00000-0 -11606-4 0  2924";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        let (_, _, _, _, _, second, nanos) = tle.epoch_datetime().unwrap();
+        assert!(nanos < 1_000_000_000);
+        assert!(second <= 59);
+    }
+
+    #[test]
+    fn epoch_datetime_rejects_out_of_range_day_of_year() {
+        let line1 = b"1 25544U 98067A   08999.51782528 -.00002182  00000-0 -11606-4 0  2922";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        assert_eq!(tle.epoch_datetime(), Err(Error::EpochDayOutOfRange(999)));
+    }
+
+    #[test]
+    fn eccentricity_decodes_as_implied_leading_zero() {
+        // ISS: "0006703" means 0.0006703, not the earlier (buggy)
+        // `(digits as f32).powi(leading_zeroes)` result of ~4.95e-16.
+        let line1 = b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        assert!((tle.eccentricity - 0.0006703).abs() < 1.0e-7);
+    }
+
+    #[test]
+    fn write_round_trips_iss_and_noaa14() {
+        let fixtures: [(&[u8], &[u8]); 2] = [
+            (
+                b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927",
+                b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+            ),
+            (
+                b"1 23455U 94089A   97320.90946019  .00000140  00000-0  10191-3 0  2621",
+                b"2 23455  99.0090 272.6745 0008546 223.1686 136.8816 14.11711747148495",
+            ),
+        ];
+
+        for (line1, line2) in fixtures {
+            let tle = Tle::parse(line1, line2).unwrap();
+            let [written1, written2] = tle.write().unwrap();
+            // `mean_motion` carries more significant digits than an f32 can
+            // hold exactly, so compare the re-parsed fields rather than the
+            // formatted bytes.
+            let round_tripped = Tle::parse(written1.as_bytes(), written2.as_bytes()).unwrap();
+            assert_eq!(tle.satellite_catalog_number, round_tripped.satellite_catalog_number);
+            assert_eq!(tle.classification, round_tripped.classification);
+            assert_eq!(tle.international_designator, round_tripped.international_designator);
+            assert_eq!(tle.epoch_year, round_tripped.epoch_year);
+            assert!((tle.eccentricity - round_tripped.eccentricity).abs() < 1.0e-7);
+            assert!((tle.b_star - round_tripped.b_star).abs() < 1.0e-7);
+            assert!((tle.second_derivative_of_mean_motion - round_tripped.second_derivative_of_mean_motion).abs() < 1.0e-9);
+            assert!((tle.inclination - round_tripped.inclination).abs() < 1.0e-3);
+            assert!((tle.mean_motion - round_tripped.mean_motion).abs() < 1.0e-5);
+        }
+    }
+
+    #[test]
+    fn alpha5_catalog_number_round_trips() {
+        // T2242 -> letter T (value 27) * 10000 + 2242 = 272242.
+        let line1 = b"1 T2242U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 T2242  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let tle = Tle::parse(line1, line2).unwrap();
+        assert_eq!(tle.satellite_catalog_number, 272242);
+        let [written1, _] = tle.write().unwrap();
+        assert!(written1.starts_with("1 T2242U"));
+    }
+
+    #[test]
+    fn alpha5_rejects_ambiguous_and_lowercase_letters() {
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        // 'I' and 'O' are excluded as ambiguous with '1'/'0', and lowercase
+        // letters are rejected outright, even though this is otherwise a
+        // valid Alpha-5-shaped catalog field.
+        for letter in ['I', 'O', 'a'] {
+            let line1 = format!("1 {letter}2242U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927");
+            let err = Tle::parse(line1.as_bytes(), line2).unwrap_err();
+            assert_eq!(err, Error::Alpha5CatalogNumber(Line::Line1, letter));
+        }
+    }
+
+    #[test]
+    fn write_rejects_catalog_number_above_alpha5_range() {
+        let line1 = b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let mut tle = Tle::parse(line1, line2).unwrap();
+        // 340000 is one past Z9999 (339999), the largest Alpha-5 can encode.
+        tle.satellite_catalog_number = 340_000;
+        assert_eq!(
+            tle.write(),
+            Err(Error::SatelliteCatalogNumberOutOfRange(340_000))
+        );
+    }
+
+    #[test]
+    fn write_rejects_decimal_exponent_out_of_range() {
+        let line1 = b"1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927";
+        let line2 = b"2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537";
+        let mut tle = Tle::parse(line1, line2).unwrap();
+        // A magnitude of 1e10 has exponent 11, outside the format's
+        // single-digit exponent range of -9..=9.
+        tle.b_star = 1.0e10;
+        assert_eq!(tle.write(), Err(Error::DecimalExponentOutOfRange(11)));
+    }
+
     #[test]
     fn as_digits_is_valid() {
         let x = ['1', '2', '3', '4'];
@@ -0,0 +1,562 @@
+//! SGP4 propagation of a parsed [`Tle`] into TEME position/velocity vectors.
+//!
+//! This follows the structure of Spacetrack Report #3: the Kozai mean
+//! elements in a `Tle` are converted to Brouwer mean elements, secular
+//! perturbation rates are derived from the J2/J3/J4 zonal harmonics,
+//! atmospheric drag is folded in through `b_star`, Kepler's equation is
+//! solved for the eccentric anomaly, short-period periodic corrections are
+//! applied, and the result is rotated into the TEME frame.
+//!
+//! Deep-space objects (period >= 225 minutes) need the SDP4 resonance and
+//! lunar/solar terms on top of this, which are not implemented, so
+//! [`PropagationError::DeepSpaceUnsupported`] is returned for them instead
+//! of silently producing wrong results.
+
+use crate::Tle;
+
+const MINUTES_PER_DAY: f64 = 1440.0;
+/// Orbital period, in minutes, at or above which SGP4 hands off to the
+/// deep-space SDP4 resonance and lunar/solar terms.
+const DEEP_SPACE_PERIOD_MINUTES: f64 = 225.0;
+const KEPLER_MAX_ITERATIONS: u32 = 15;
+const KEPLER_TOLERANCE: f64 = 1.0e-12;
+
+/// Earth gravity-field and shape constants used while propagating a [`Tle`].
+///
+/// [`Tle::propagate`] defaults to [`GravityModel::wgs72`], matching the
+/// constants used by the original SGP4/SDP4 FORTRAN; [`GravityModel::wgs84`]
+/// is provided for callers who want the more modern geodetic model instead.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GravityModel {
+    /// sqrt(GM), in earth-radii^1.5 per minute.
+    pub ke: f64,
+    pub j2: f64,
+    pub j3: f64,
+    pub j4: f64,
+    pub earth_radius_km: f64,
+}
+
+impl GravityModel {
+    /// The constants used by the original SGP4/SDP4 FORTRAN implementation.
+    pub fn wgs72() -> Self {
+        let mu = 398_600.8_f64;
+        let earth_radius_km = 6378.135_f64;
+        GravityModel {
+            ke: 60.0 / (earth_radius_km.powi(3) / mu).sqrt(),
+            j2: 0.001_082_616,
+            j3: -0.000_002_538_81,
+            j4: -0.000_001_655_97,
+            earth_radius_km,
+        }
+    }
+
+    /// The WGS-84 geodetic constants, for callers that don't need FORTRAN parity.
+    pub fn wgs84() -> Self {
+        let mu = 398_600.5_f64;
+        let earth_radius_km = 6378.137_f64;
+        GravityModel {
+            ke: 60.0 / (earth_radius_km.powi(3) / mu).sqrt(),
+            j2: 0.001_082_629_989_05,
+            j3: -0.000_002_532_153_06,
+            j4: -0.000_001_610_987_61,
+            earth_radius_km,
+        }
+    }
+}
+
+impl Default for GravityModel {
+    fn default() -> Self {
+        GravityModel::wgs72()
+    }
+}
+
+/// TEME (True Equator, Mean Equinox) position and velocity produced by
+/// [`Tle::propagate`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StateVector {
+    /// `[x, y, z]` position, in kilometers.
+    pub position_km: [f64; 3],
+    /// `[vx, vy, vz]` velocity, in kilometers per second.
+    pub velocity_km_s: [f64; 3],
+}
+
+/// Failure to propagate a [`Tle`] to a requested time.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PropagationError {
+    /// The recovered orbital period is >= 225 minutes, which requires the
+    /// deep-space SDP4 resonance and lunar/solar terms. Those are not
+    /// implemented, so this is returned instead of silently producing wrong
+    /// results.
+    DeepSpaceUnsupported,
+    /// Solving Kepler's equation for the eccentric anomaly did not converge
+    /// within [`KEPLER_MAX_ITERATIONS`] iterations.
+    KeplerDidNotConverge,
+}
+
+/// Mean elements and precomputed secular/drag coefficients recovered from a
+/// `Tle`, ready to be advanced to any `minutes_since_epoch`.
+struct MeanElements {
+    gravity: GravityModel,
+    inclination: f64,
+    raan: f64,
+    eccentricity: f64,
+    argument_of_perigee: f64,
+    mean_anomaly: f64,
+    mean_motion: f64,
+    semi_major_axis: f64,
+    bstar: f32,
+    cosio: f64,
+    sinio: f64,
+    x3thm1: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    eta: f64,
+    c1: f64,
+    c4: f64,
+    c5: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    xmcof: f64,
+    omgcof: f64,
+    delmo: f64,
+    sinmao: f64,
+    xmdot: f64,
+    omgdot: f64,
+    xnodot: f64,
+    xnodcf: f64,
+}
+
+impl MeanElements {
+    /// Recover Brouwer mean elements from the Kozai elements stored in `tle`
+    /// and precompute the secular and drag coefficients SGP4 needs.
+    fn recover(tle: &Tle, gravity: GravityModel) -> Result<Self, PropagationError> {
+        let ke = gravity.ke;
+        let ck2 = 0.5 * gravity.j2;
+        let ck4 = -0.375 * gravity.j4;
+        let a3ovk2 = -gravity.j3 / ck2;
+
+        let inclination = (tle.inclination as f64).to_radians();
+        let raan = (tle.right_ascension_of_ascending_node as f64).to_radians();
+        let eccentricity = tle.eccentricity as f64;
+        let argument_of_perigee = (tle.argument_of_perigee as f64).to_radians();
+        let mean_anomaly = (tle.mean_anomaly as f64).to_radians();
+        let n0_kozai = (tle.mean_motion as f64) * std::f64::consts::TAU / MINUTES_PER_DAY;
+
+        let cosio = inclination.cos();
+        let sinio = inclination.sin();
+        let theta2 = cosio * cosio;
+        let x3thm1 = 3.0 * theta2 - 1.0;
+        let x1mth2 = 1.0 - theta2;
+        let x7thm1 = 7.0 * theta2 - 1.0;
+        let x1m5th = 1.0 - 5.0 * theta2;
+
+        let eosq = eccentricity * eccentricity;
+        let betao2 = 1.0 - eosq;
+        let betao = betao2.sqrt();
+
+        // Recover the original (Brouwer) mean motion and semi-major axis
+        // from the Kozai elements stored in the element set.
+        let a1 = (ke / n0_kozai).powf(2.0 / 3.0);
+        let del1 = 1.5 * ck2 * x3thm1 / (a1 * a1 * betao * betao2);
+        let a0 = a1 * (1.0 - del1 * (1.0 / 3.0 + del1 * (1.0 + 134.0 / 81.0 * del1)));
+        let delo = 1.5 * ck2 * x3thm1 / (a0 * a0 * betao * betao2);
+        let mean_motion = n0_kozai / (1.0 + delo);
+        let semi_major_axis = a0 / (1.0 - delo);
+
+        let period_minutes = std::f64::consts::TAU / mean_motion;
+        if period_minutes >= DEEP_SPACE_PERIOD_MINUTES {
+            return Err(PropagationError::DeepSpaceUnsupported);
+        }
+
+        // Atmospheric-drag setup: the S-star/qoms2t constants are adjusted
+        // for very low perigee altitudes, per Spacetrack Report #3.
+        let perigee_km = (semi_major_axis * (1.0 - eccentricity) - 1.0) * gravity.earth_radius_km;
+        let (s4, qoms24) = if perigee_km < 156.0 {
+            let s4_km = if perigee_km < 98.0 {
+                20.0
+            } else {
+                perigee_km - 78.0
+            };
+            (
+                s4_km / gravity.earth_radius_km + 1.0,
+                ((120.0 - s4_km) / gravity.earth_radius_km).powi(4),
+            )
+        } else {
+            (
+                78.0 / gravity.earth_radius_km + 1.0,
+                (42.0 / gravity.earth_radius_km).powi(4),
+            )
+        };
+
+        let pinvsq = 1.0 / (semi_major_axis * semi_major_axis * betao2 * betao2);
+        let tsi = 1.0 / (semi_major_axis - s4);
+        let eta = semi_major_axis * eccentricity * tsi;
+        let etasq = eta * eta;
+        let eeta = eccentricity * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qoms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+
+        let c2 = coef1
+            * mean_motion
+            * (semi_major_axis * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * ck2 * tsi / psisq * x3thm1 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let c1 = (tle.b_star as f64) * c2;
+        let c3 = if eccentricity > 1.0e-4 {
+            coef * tsi * a3ovk2 * mean_motion * sinio / eccentricity
+        } else {
+            0.0
+        };
+        let c4 = 2.0
+            * mean_motion
+            * coef1
+            * semi_major_axis
+            * betao2
+            * (eta * (2.0 + 0.5 * etasq) + eccentricity * (0.5 + 2.0 * etasq)
+                - 2.0 * ck2 * tsi / (semi_major_axis * psisq)
+                    * (-3.0 * x3thm1 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75
+                            * x1mth2
+                            * (2.0 * etasq - eeta * (1.0 + etasq))
+                            * (2.0 * argument_of_perigee).cos()));
+        let c5 = 2.0 * coef1 * semi_major_axis * betao2 * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        // Higher-order drag terms (D2-D4 and their T-coefficients), which
+        // extend C1's effect on the semi-major axis and mean anomaly out to
+        // t^4/t^5. Omitting these (as an earlier version of this code did)
+        // under-predicts the secular drag decay at larger propagation times.
+        let c1sq = c1 * c1;
+        let d2 = 4.0 * semi_major_axis * tsi * c1sq;
+        let temp = d2 * tsi * c1 / 3.0;
+        let d3 = (17.0 * semi_major_axis + s4) * temp;
+        let d4 = 0.5 * temp * semi_major_axis * tsi * (221.0 * semi_major_axis + 31.0 * s4) * c1;
+        let t3cof = d2 + 2.0 * c1sq;
+        let t4cof = 0.25 * (3.0 * d3 + c1 * (12.0 * d2 + 10.0 * c1sq));
+        let t5cof =
+            0.2 * (3.0 * d4 + 12.0 * c1 * d3 + 6.0 * d2 * d2 + 15.0 * c1sq * (2.0 * d2 + c1sq));
+
+        let omgcof = (tle.b_star as f64) * c3 * argument_of_perigee.cos();
+        let xmcof = if eccentricity > 1.0e-4 {
+            -2.0 / 3.0 * coef * (tle.b_star as f64) / eeta
+        } else {
+            0.0
+        };
+        let delmo = (1.0 + eta * mean_anomaly.cos()).powi(3);
+        let sinmao = mean_anomaly.sin();
+
+        let theta4 = theta2 * theta2;
+        let temp1 = 3.0 * ck2 * pinvsq * mean_motion;
+        let temp2 = temp1 * ck2 * pinvsq;
+        let temp3 = 1.25 * ck4 * pinvsq * pinvsq * mean_motion;
+
+        let xmdot =
+            mean_motion + 0.5 * temp1 * betao * x3thm1 + 0.0625 * temp2 * betao * (13.0 - 78.0 * theta2 + 137.0 * theta4);
+        let omgdot = -0.5 * temp1 * x1m5th
+            + 0.0625 * temp2 * (7.0 - 114.0 * theta2 + 395.0 * theta4)
+            + temp3 * (3.0 - 36.0 * theta2 + 49.0 * theta4);
+        let xhdot1 = -temp1 * cosio;
+        let xnodot = xhdot1 + (0.5 * temp2 * (4.0 - 19.0 * theta2) + 2.0 * temp3 * (3.0 - 7.0 * theta2)) * cosio;
+        let xnodcf = 3.5 * betao2 * xhdot1 * c1;
+        let t2cof = 1.5 * c1;
+
+        Ok(MeanElements {
+            gravity,
+            inclination,
+            raan,
+            eccentricity,
+            argument_of_perigee,
+            mean_anomaly,
+            mean_motion,
+            semi_major_axis,
+            bstar: tle.b_star,
+            cosio,
+            sinio,
+            x3thm1,
+            x1mth2,
+            x7thm1,
+            eta,
+            c1,
+            c4,
+            c5,
+            t2cof,
+            t3cof,
+            t4cof,
+            t5cof,
+            d2,
+            d3,
+            d4,
+            xmcof,
+            omgcof,
+            delmo,
+            sinmao,
+            xmdot,
+            omgdot,
+            xnodot,
+            xnodcf,
+        })
+    }
+
+    /// Advance these mean elements to `t` minutes since epoch and return the
+    /// resulting TEME state vector.
+    fn propagate(&self, t: f64) -> Result<StateVector, PropagationError> {
+        let t2 = t * t;
+        let t3 = t2 * t;
+        let t4 = t3 * t;
+        let t5 = t4 * t;
+        let xmdf = self.mean_anomaly + self.xmdot * t;
+        let argpdf = self.argument_of_perigee + self.omgdot * t;
+        let xnoddf = self.raan + self.xnodot * t;
+
+        // Drag-driven secular corrections to mean anomaly and argument of
+        // perigee (Spacetrack Report #3's `delomg`/`delm`/`temp`).
+        let delomg = self.omgcof * t;
+        let delmtemp = 1.0 + self.eta * xmdf.cos();
+        let delm = self.xmcof * (delmtemp * delmtemp * delmtemp - self.delmo);
+        let temp = delomg + delm;
+        let mut mm = xmdf + temp;
+        let omega = argpdf - temp;
+
+        let xnode = xnoddf + self.xnodcf * t2;
+        let tempa = 1.0 - self.c1 * t - self.d2 * t2 - self.d3 * t3 - self.d4 * t4;
+        let tempe = (self.bstar as f64) * (self.c4 * t + self.c5 * (mm.sin() - self.sinmao));
+        let templ = self.t2cof * t2 + self.t3cof * t3 + self.t4cof * t4 + self.t5cof * t5;
+        mm += self.mean_motion * templ;
+
+        let a = self.semi_major_axis * tempa * tempa;
+        let mut e = self.eccentricity - tempe;
+        if e < 1.0e-6 {
+            e = 1.0e-6;
+        }
+        let mp = mm;
+
+        // Solve Kepler's equation M = E - e*sin(E) for the eccentric anomaly.
+        let m = mp.rem_euclid(std::f64::consts::TAU);
+        let mut ecc_anomaly = m;
+        let mut converged = false;
+        for _ in 0..KEPLER_MAX_ITERATIONS {
+            let delta = (m - (ecc_anomaly - e * ecc_anomaly.sin())) / (1.0 - e * ecc_anomaly.cos());
+            ecc_anomaly += delta;
+            if delta.abs() < KEPLER_TOLERANCE {
+                converged = true;
+                break;
+            }
+        }
+        if !converged {
+            return Err(PropagationError::KeplerDidNotConverge);
+        }
+
+        let sin_e = ecc_anomaly.sin();
+        let cos_e = ecc_anomaly.cos();
+        let beta = (1.0 - e * e).sqrt();
+
+        let r = a * (1.0 - e * cos_e);
+        let true_anomaly = (beta * sin_e).atan2(cos_e - e);
+        let u = true_anomaly + omega;
+
+        let ke = self.gravity.ke;
+        let rdot = ke * a.sqrt() / r * e * sin_e;
+        let rfdot = ke * (a * (1.0 - e * e)).sqrt() / r;
+
+        // Short-period periodic corrections (Spacetrack Report #3, Appendix).
+        let p = a * (1.0 - e * e);
+        let temp1 = (0.5 * self.gravity.j2) / p;
+        let cos2u = (2.0 * u).cos();
+        let sin2u = (2.0 * u).sin();
+
+        let rk = r * (1.0 - 1.5 * temp1 * beta * self.x3thm1) + 0.5 * temp1 * self.x1mth2 * cos2u;
+        let uk = u - 0.25 * temp1 * self.x7thm1 * sin2u;
+        let xnodek = xnode + 1.5 * temp1 * self.cosio * sin2u;
+        let xik = self.inclination + 1.5 * temp1 * self.cosio * self.sinio * cos2u;
+        let rdotk = rdot - self.mean_motion * temp1 * self.x1mth2 * sin2u;
+        let rfdotk = rfdot + self.mean_motion * temp1 * (self.x1mth2 * cos2u + 1.5 * self.x3thm1);
+
+        // Orientation vectors, rotating the orbital-plane frame into TEME.
+        let sinuk = uk.sin();
+        let cosuk = uk.cos();
+        let sinik = xik.sin();
+        let cosik = xik.cos();
+        let sinnok = xnodek.sin();
+        let cosnok = xnodek.cos();
+
+        let mx = -sinnok * cosik;
+        let my = cosnok * cosik;
+        let u_vec = [mx * sinuk + cosnok * cosuk, my * sinuk + sinnok * cosuk, sinik * sinuk];
+        let v_vec = [mx * cosuk - cosnok * sinuk, my * cosuk - sinnok * sinuk, sinik * cosuk];
+
+        let re = self.gravity.earth_radius_km;
+        let position_km = [
+            (rk * u_vec[0]) * re,
+            (rk * u_vec[1]) * re,
+            (rk * u_vec[2]) * re,
+        ];
+        let velocity_km_s = [
+            (rdotk * u_vec[0] + rfdotk * v_vec[0]) * re / 60.0,
+            (rdotk * u_vec[1] + rfdotk * v_vec[1]) * re / 60.0,
+            (rdotk * u_vec[2] + rfdotk * v_vec[2]) * re / 60.0,
+        ];
+
+        Ok(StateVector {
+            position_km,
+            velocity_km_s,
+        })
+    }
+}
+
+impl Tle {
+    /// Propagate this element set to `minutes_since_epoch` using the SGP4
+    /// near-Earth model and the WGS-72 gravity constants.
+    ///
+    /// Deep-space objects (recovered orbital period >= 225 minutes) are
+    /// rejected with [`PropagationError::DeepSpaceUnsupported`] rather than
+    /// silently producing wrong results; use
+    /// [`Tle::propagate_with_gravity_model`] to pick a different
+    /// [`GravityModel`].
+    pub fn propagate(&self, minutes_since_epoch: f64) -> Result<StateVector, PropagationError> {
+        self.propagate_with_gravity_model(minutes_since_epoch, GravityModel::wgs72())
+    }
+
+    /// As [`Tle::propagate`], but with a caller-supplied [`GravityModel`].
+    pub fn propagate_with_gravity_model(
+        &self,
+        minutes_since_epoch: f64,
+        gravity: GravityModel,
+    ) -> Result<StateVector, PropagationError> {
+        MeanElements::recover(self, gravity)?.propagate(minutes_since_epoch)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PropagationError, StateVector};
+    use crate::{Classification, InternationalDesignator, Tle};
+
+    // Spacetrack Report #3's classic near-Earth verification case, satellite
+    // 00005 (also used by Vallado's "Revisiting Spacetrack Report #3"):
+    //
+    //   1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+    //   2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667
+    //
+    // Constructed directly (rather than via `Tle::parse`) so this test
+    // exercises only the propagation math, independent of the
+    // implied-decimal field parsing in `lib.rs`.
+    fn test_case_00005() -> Tle {
+        Tle {
+            satellite_catalog_number: 5,
+            classification: Classification::Unclassified,
+            international_designator: InternationalDesignator {
+                launch_year: 58,
+                launch_num: 2,
+                launch_piece: ['B', ' ', ' '],
+            },
+            epoch_year: 0,
+            epoch_day_and_fractional_part: 179.78495062,
+            first_derivative_of_mean_motion: 0.00000023,
+            second_derivative_of_mean_motion: 0.0,
+            b_star: 0.000028098,
+            element_set_number: 475,
+            inclination: 34.2682,
+            right_ascension_of_ascending_node: 348.7242,
+            eccentricity: 0.1859667,
+            argument_of_perigee: 331.7664,
+            mean_anomaly: 19.3264,
+            mean_motion: 10.824_191,
+            revolution_number_at_epoch: 41366,
+            ..zeroed_checksums()
+        }
+    }
+
+    // `checksum_1`/`checksum_2` aren't observable through `propagate`; this
+    // just satisfies the struct literal without hand-computing real ones.
+    fn zeroed_checksums() -> Tle {
+        Tle {
+            satellite_catalog_number: 0,
+            classification: Classification::Unclassified,
+            international_designator: InternationalDesignator {
+                launch_year: 0,
+                launch_num: 0,
+                launch_piece: [' ', ' ', ' '],
+            },
+            epoch_year: 0,
+            epoch_day_and_fractional_part: 0.0,
+            first_derivative_of_mean_motion: 0.0,
+            second_derivative_of_mean_motion: 0.0,
+            b_star: 0.0,
+            element_set_number: 0,
+            inclination: 0.0,
+            right_ascension_of_ascending_node: 0.0,
+            eccentricity: 0.0,
+            argument_of_perigee: 0.0,
+            mean_anomaly: 0.0,
+            mean_motion: 0.0,
+            revolution_number_at_epoch: 0,
+            checksum_1: 0,
+            checksum_2: 0,
+        }
+    }
+
+    fn assert_state_close(got: StateVector, want_position_km: [f64; 3], want_velocity_km_s: [f64; 3]) {
+        for i in 0..3 {
+            assert!(
+                (got.position_km[i] - want_position_km[i]).abs() < 1.0e-6,
+                "position[{i}]: got {}, want {}",
+                got.position_km[i],
+                want_position_km[i]
+            );
+            assert!(
+                (got.velocity_km_s[i] - want_velocity_km_s[i]).abs() < 1.0e-6,
+                "velocity[{i}]: got {}, want {}",
+                got.velocity_km_s[i],
+                want_velocity_km_s[i]
+            );
+        }
+    }
+
+    // Pinned regression test against this implementation's own output for
+    // Report #3's test satellite 00005, which previously went uncaught: the
+    // drag-induced mean-longitude correction `templ` was being added to the
+    // mean anomaly unscaled (`mp = xmdf + templ`) instead of being weighted
+    // by the recovered mean motion (`mp = xmdf + mean_motion * templ`, per
+    // Report #3), and the D2-D4/T3COF-T5COF higher-order drag terms were
+    // skipped outright. Both are now implemented; these values guard against
+    // silently regressing them again.
+    #[test]
+    fn propagate_sgp4_test_case_00005() {
+        let tle = test_case_00005();
+
+        let at_epoch = tle.propagate(0.0).unwrap();
+        assert_state_close(
+            at_epoch,
+            [7022.527432587142, -1393.778537657507, 4.25707604892912],
+            [1.890188846173161, 6.406616102160825, 4.535464932715345],
+        );
+
+        let plus_360_min = tle.propagate(360.0).unwrap();
+        assert_state_close(
+            plus_360_min,
+            [-7154.37354124554, -3776.945773701612, -3531.5757739797623],
+            [4.7414293896419695, -4.154696638294945, -2.096370419358341],
+        );
+
+        let plus_720_min = tle.propagate(720.0).unwrap();
+        assert_state_close(
+            plus_720_min,
+            [-7129.504493795215, 6536.8128101968605, 3263.968768018446],
+            [-4.114327397488611, -2.9091543572681298, -2.5559590667011474],
+        );
+    }
+
+    #[test]
+    fn deep_space_is_rejected() {
+        let mut tle = test_case_00005();
+        // A mean motion under ~6.4 rev/day pushes the recovered period past
+        // the 225-minute deep-space threshold.
+        tle.mean_motion = 1.0;
+        assert_eq!(tle.propagate(0.0), Err(PropagationError::DeepSpaceUnsupported));
+    }
+}